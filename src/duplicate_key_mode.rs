@@ -0,0 +1,24 @@
+/// How the parser should handle a key that appears more than once in the
+/// same scope.
+///
+/// Paradox script legitimately repeats keys in a single scope (`add_modifier
+/// = { ... }` appearing many times, or repeated `building = { ... }`
+/// entries), and silently keeping only the last one loses data.
+// `main`'s CLI doesn't expose a way to pick a mode yet, so only
+// `MergeIntoArray` (the default) is ever constructed from this binary;
+// the other variants are reachable through `Parser::with_duplicate_key_mode`
+// and exercised by its tests.
+#[allow(dead_code)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DuplicateKeyMode {
+    /// Keep only the most recently seen value for a key.
+    Overwrite,
+    /// Promote the existing value to a `Value::Array` (if it isn't one
+    /// already) and push subsequent values onto it. Matches how Paradox
+    /// engines actually interpret these files.
+    #[default]
+    MergeIntoArray,
+    /// Treat a repeated key as a parse error instead of silently resolving
+    /// it one way or the other.
+    Error,
+}
@@ -1,4 +1,4 @@
-use clap::Parser;
+use clap::Parser as ClapParser;
 use std::fs;
 
 pub type Error = (String, logos::Span);
@@ -8,15 +8,14 @@ pub type Result<T> = std::result::Result<T, Error>;
 mod token;
 mod value;
 
-mod parse_array;
-mod parse_next_value;
-mod parse_program;
+mod duplicate_key_mode;
 mod parse_simple_value;
-mod parse_variables;
+mod parser;
+mod visitor;
 
-use parse_program::parse_program;
+use parser::Parser;
 
-#[derive(Parser, Debug)]
+#[derive(ClapParser, Debug)]
 #[clap(author = "Joaqim Planstedt", version, about)]
 /// Application configuration
 struct Args {
@@ -33,27 +32,30 @@ fn main() {
     let filename = args.file.expect("Expected file argument");
     let src = fs::read_to_string(&filename).expect("Failed to read file");
 
-    match parse_program(&src) {
-        Ok(value) => {
-            if args.ast {
-                println!("{:#?}", value);
-            } else {
-                match serde_json::to_string_pretty(&value) {
-                    Ok(val) => println!("{}", val),
-                    Err(err) => {
-                        eprintln!("Failed to serialize: {}", err);
-                    }
-                }
+    let mut parser = Parser::new(&src);
+    let value = parser.parse();
+    let errors = parser.take_errors();
+
+    if args.ast {
+        println!("{:#?}", value);
+    } else {
+        match serde_json::to_string_pretty(&value) {
+            Ok(val) => println!("{}", val),
+            Err(err) => {
+                eprintln!("Failed to serialize: {}", err);
             }
         }
-        Err((msg, span)) => {
-            use ariadne::{ColorGenerator, Label, Report, ReportKind, Source};
+    }
+
+    if !errors.is_empty() {
+        use ariadne::{ColorGenerator, Label, Report, ReportKind, Source};
 
-            let mut colors = ColorGenerator::new();
+        let mut colors = ColorGenerator::new();
 
+        for (msg, span) in errors {
             let a = colors.next();
 
-            Report::build(ReportKind::Error, (&filename, 12..12))
+            Report::build(ReportKind::Error, &filename, 12)
                 .with_message("Failed to parse Input".to_string())
                 .with_label(
                     Label::new((&filename, span))
@@ -64,5 +66,5 @@ fn main() {
                 .eprint((&filename, Source::from(&src)))
                 .unwrap();
         }
-    };
+    }
 }
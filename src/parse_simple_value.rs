@@ -3,8 +3,14 @@ use logos::Span;
 
 pub fn parse_simple_value(token: Token<'_>) -> Result<Value<'_>> {
     match token {
-        Token::Bool(b) => Ok(Value::Bool(b)),
+        Token::Bool(literal) => Ok(Value::Bool(literal.value, literal.repr)),
         Token::Null => Ok(Value::Null),
+        Token::Date(date) => Ok(Value::Date {
+            year: date.year,
+            month: date.month,
+            day: date.day,
+            hour: date.hour,
+        }),
         Token::Float(n) => Ok(Value::Float(n)),
         Token::Integer(n) => Ok(Value::Integer(n)),
         Token::String(s) => Ok(Value::String(s)),
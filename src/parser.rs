@@ -0,0 +1,478 @@
+use logos::Span;
+use ordered_hash_map::OrderedHashMap;
+
+use crate::duplicate_key_mode::DuplicateKeyMode;
+use crate::token::Operator;
+use crate::value::Value;
+use crate::visitor::{parse_events, Visitor};
+use crate::Error;
+
+/// Parses a source buffer into a best-effort `Value`, accumulating every
+/// diagnostic encountered instead of bailing on the first malformed
+/// statement.
+///
+/// Modeled on how swc replaced its old abort-on-first-error flow with a
+/// `Parser` that owns its `Handler`: parsing never stops, it records what
+/// went wrong and resynchronizes at the next safe point so the rest of the
+/// file still gets parsed. Internally this drives the same event stream as
+/// [`crate::visitor::parse_events`] and assembles the tree from it, so the
+/// streaming path and the tree-building path can never drift apart.
+pub struct Parser<'source> {
+    source: &'source str,
+    duplicate_key_mode: DuplicateKeyMode,
+    errors: Vec<Error>,
+}
+
+impl<'source> Parser<'source> {
+    pub fn new(source: &'source str) -> Self {
+        Self::with_duplicate_key_mode(source, DuplicateKeyMode::default())
+    }
+
+    /// Like [`Parser::new`], but with explicit control over how repeated
+    /// keys in the same scope are resolved.
+    pub fn with_duplicate_key_mode(source: &'source str, duplicate_key_mode: DuplicateKeyMode) -> Self {
+        Self {
+            source,
+            duplicate_key_mode,
+            errors: Vec::new(),
+        }
+    }
+
+    /// Parse the whole input, returning the best-effort `Value` that could
+    /// be assembled. Call [`Parser::take_errors`] afterwards to see what, if
+    /// anything, failed along the way.
+    pub fn parse(&mut self) -> Value<'source> {
+        let mut builder = ValueBuilder::new(self.duplicate_key_mode);
+        let mut errors = parse_events(self.source, &mut builder);
+        let (value, duplicate_key_errors) = builder.finish();
+        errors.extend(duplicate_key_errors);
+        self.errors = errors;
+        value
+    }
+
+    /// Drain the diagnostics collected while parsing.
+    pub fn take_errors(&mut self) -> Vec<Error> {
+        std::mem::take(&mut self.errors)
+    }
+}
+
+/// A single `{ ... }` scope being assembled. Keyed entries and bare
+/// elements accumulate separately since a scope's shape (object vs array)
+/// isn't known until it closes. `entries` is already keyed by name (rather
+/// than a `Vec` scanned linearly on every push) since large save files can
+/// have thousands of distinct keys in a single scope. Every keyed entry,
+/// root or nested, carries the [`Operator`] it was assigned with, matching
+/// [`Value::Object`]'s shape.
+struct Frame<'source> {
+    is_root: bool,
+    pending_key: Option<(&'source str, Operator, Span)>,
+    entries: OrderedHashMap<&'source str, (Operator, Value<'source>)>,
+    elements: Vec<(Value<'source>, Span)>,
+}
+
+impl<'source> Frame<'source> {
+    fn new(is_root: bool) -> Self {
+        Self {
+            is_root,
+            pending_key: None,
+            entries: OrderedHashMap::new(),
+            elements: Vec::new(),
+        }
+    }
+
+    fn push_value(
+        &mut self,
+        value: Value<'source>,
+        span: Span,
+        mode: DuplicateKeyMode,
+        errors: &mut Vec<Error>,
+    ) {
+        match self.pending_key.take() {
+            Some((key, op, key_span)) => {
+                merge_into_map(&mut self.entries, key, op, value, key_span, mode, errors)
+            }
+            None => self.elements.push((value, span)),
+        }
+    }
+
+    /// A scope that saw any keyed entries is an object; otherwise it's an
+    /// array, unless any of its bare elements are themselves objects, in
+    /// which case those objects get merged together into one. The root scope
+    /// is always an object, since it has no enclosing braces to disambiguate.
+    /// Whenever a scope closes as an object, its bare elements that are not
+    /// objects are dropped.
+    ///
+    /// Bare object elements are merged key-by-key through [`merge_into_map`]
+    /// rather than a raw [`OrderedHashMap::extend`], so a key shared between
+    /// an explicit entry and a bare sub-object (or between two bare
+    /// sub-objects) is resolved according to `mode` instead of silently
+    /// overwritten.
+    fn close(self, mode: DuplicateKeyMode, errors: &mut Vec<Error>) -> Value<'source> {
+        let mut map = self.entries;
+
+        if self.is_root || !map.is_empty() {
+            for (element, span) in self.elements {
+                if let Value::Object(obj) = element {
+                    for (key, (op, value)) in obj {
+                        merge_into_map(&mut map, key, op, value, span.clone(), mode, errors);
+                    }
+                }
+            }
+            return Value::Object(map);
+        }
+
+        if self
+            .elements
+            .iter()
+            .any(|(value, _)| matches!(value, Value::Object(_)))
+        {
+            let mut map = OrderedHashMap::new();
+            for (element, span) in self.elements {
+                if let Value::Object(obj) = element {
+                    for (key, (op, value)) in obj {
+                        merge_into_map(&mut map, key, op, value, span.clone(), mode, errors);
+                    }
+                }
+            }
+            return Value::Object(map);
+        }
+
+        if self.elements.is_empty() {
+            return Value::Empty;
+        }
+
+        Value::Array(self.elements.into_iter().map(|(value, _)| value).collect())
+    }
+}
+
+/// Resolve a repeated key in `map` according to `mode`. Used both while a
+/// scope is still accumulating its own entries and to fold bare sub-objects
+/// into a scope's object, so either path resolves a collision the same way
+/// instead of bypassing `mode`.
+fn merge_into_map<'source>(
+    map: &mut OrderedHashMap<&'source str, (Operator, Value<'source>)>,
+    key: &'source str,
+    op: Operator,
+    value: Value<'source>,
+    span: Span,
+    mode: DuplicateKeyMode,
+    errors: &mut Vec<Error>,
+) {
+    match map.get_mut(key) {
+        None => {
+            map.insert(key, (op, value));
+        }
+        Some(existing) => match mode {
+            DuplicateKeyMode::Overwrite => *existing = (op, value),
+            DuplicateKeyMode::MergeIntoArray => merge_into_array(&mut existing.1, value),
+            DuplicateKeyMode::Error => {
+                errors.push((format!("duplicate key '{}'", key), span));
+            }
+        },
+    }
+}
+
+/// Promote `existing` to a `Value::Array` (if it isn't one already) and
+/// push `value` onto it.
+fn merge_into_array<'source>(existing: &mut Value<'source>, value: Value<'source>) {
+    match existing {
+        Value::Array(items) => items.push(value),
+        _ => {
+            let previous = std::mem::replace(existing, Value::Empty);
+            *existing = Value::Array(vec![previous, value]);
+        }
+    }
+}
+
+/// A [`Visitor`] that reassembles the event stream into a `Value` tree; see
+/// [`Frame::close`] for how each scope's shape is decided.
+///
+/// Repeated keys within a scope are resolved according to `mode`.
+struct ValueBuilder<'source> {
+    stack: Vec<Frame<'source>>,
+    result: Option<Value<'source>>,
+    mode: DuplicateKeyMode,
+    errors: Vec<Error>,
+}
+
+impl<'source> ValueBuilder<'source> {
+    fn new(mode: DuplicateKeyMode) -> Self {
+        Self {
+            stack: Vec::new(),
+            result: None,
+            mode,
+            errors: Vec::new(),
+        }
+    }
+
+    fn finish(self) -> (Value<'source>, Vec<Error>) {
+        (self.result.unwrap_or(Value::Empty), self.errors)
+    }
+}
+
+impl<'source> Visitor<'source> for ValueBuilder<'source> {
+    fn object_start(&mut self, _span: Span) {
+        let is_root = self.stack.is_empty();
+        self.stack.push(Frame::new(is_root));
+    }
+
+    fn object_close(&mut self, span: Span) {
+        let frame = self
+            .stack
+            .pop()
+            .expect("object_close without a matching object_start");
+        let value = frame.close(self.mode, &mut self.errors);
+        match self.stack.last_mut() {
+            Some(parent) => parent.push_value(value, span, self.mode, &mut self.errors),
+            None => self.result = Some(value),
+        }
+    }
+
+    fn key(&mut self, key: &'source str, operator: Operator, span: Span) {
+        if let Some(frame) = self.stack.last_mut() {
+            frame.pending_key = Some((key, operator, span));
+        }
+    }
+
+    fn scalar(&mut self, value: Value<'source>, span: Span) {
+        if let Some(frame) = self.stack.last_mut() {
+            frame.push_value(value, span, self.mode, &mut self.errors);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::value::BoolRepr;
+
+    #[test]
+    fn test_parse_empty_object() {
+        let mut parser = Parser::new("}");
+        let value = parser.parse();
+        assert!(matches!(value, Value::Object(_)));
+        assert!(parser.take_errors().is_empty());
+    }
+
+    #[test]
+    fn test_parse_single_key_value_pair() {
+        let mut parser = Parser::new("key = value }");
+        parser.parse();
+        assert!(parser.take_errors().is_empty());
+    }
+
+    #[test]
+    fn test_recovers_from_bad_statement_and_keeps_parsing() {
+        let mut parser = Parser::new("key1 = } key2 = value2 }");
+        let value = parser.parse();
+        let variables = match value {
+            Value::Object(variables) => variables,
+            _ => panic!(),
+        };
+        assert_eq!(parser.take_errors().len(), 1);
+        assert!(variables.get("key2").is_some());
+    }
+
+    #[test]
+    fn test_synchronizes_past_broken_nested_block() {
+        let mut parser = Parser::new("a = { b = } c = value }");
+        let value = parser.parse();
+        let variables = match value {
+            Value::Object(variables) => variables,
+            _ => panic!(),
+        };
+        assert_eq!(parser.take_errors().len(), 1);
+        match variables.get("a") {
+            Some((_, Value::Object(obj))) => assert_eq!(
+                obj.get("c"),
+                Some(&(Operator::Equal, Value::String("value")))
+            ),
+            _ => panic!(),
+        }
+    }
+
+    #[test]
+    fn test_parses_comparison_and_compound_operators() {
+        let mut parser =
+            Parser::new("age >= 20 has_trait ?= brave tag_a == TAG tag_b != OTHER }");
+        let value = parser.parse();
+        let variables = match value {
+            Value::Object(variables) => variables,
+            _ => panic!(),
+        };
+        assert!(parser.take_errors().is_empty());
+        assert_eq!(
+            variables.get("age").map(|(op, _)| *op),
+            Some(Operator::GreaterThanOrEqual)
+        );
+        assert_eq!(
+            variables.get("has_trait").map(|(op, _)| *op),
+            Some(Operator::ConditionalEqual)
+        );
+        assert_eq!(
+            variables.get("tag_a").map(|(op, _)| *op),
+            Some(Operator::EqualEqual)
+        );
+        assert_eq!(
+            variables.get("tag_b").map(|(op, _)| *op),
+            Some(Operator::NotEqual)
+        );
+    }
+
+    #[test]
+    fn test_parses_date_literals() {
+        let mut parser = Parser::new("start_date = 1836.1.1 birth = 1811.3.5.12 }");
+        let value = parser.parse();
+        let variables = match value {
+            Value::Object(variables) => variables,
+            _ => panic!(),
+        };
+        assert!(parser.take_errors().is_empty());
+        assert!(matches!(
+            variables.get("start_date"),
+            Some((
+                _,
+                Value::Date {
+                    year: 1836,
+                    month: 1,
+                    day: 1,
+                    hour: None
+                }
+            ))
+        ));
+        assert!(matches!(
+            variables.get("birth"),
+            Some((
+                _,
+                Value::Date {
+                    year: 1811,
+                    month: 3,
+                    day: 5,
+                    hour: Some(12)
+                }
+            ))
+        ));
+    }
+
+    #[test]
+    fn test_date_component_out_of_range_is_reported_not_a_panic() {
+        let mut parser = Parser::new("start_date = 1836.999.1 }");
+        let value = parser.parse();
+        assert!(matches!(value, Value::Object(_)));
+        assert_eq!(parser.take_errors().len(), 1);
+    }
+
+    #[test]
+    fn test_parses_yes_no_and_true_false_booleans() {
+        let mut parser =
+            Parser::new("is_capital = yes has_port = no enabled = true disabled = false }");
+        let value = parser.parse();
+        let variables = match value {
+            Value::Object(variables) => variables,
+            _ => panic!(),
+        };
+        assert!(parser.take_errors().is_empty());
+        assert!(matches!(
+            variables.get("is_capital"),
+            Some((_, Value::Bool(true, BoolRepr::YesNo)))
+        ));
+        assert!(matches!(
+            variables.get("has_port"),
+            Some((_, Value::Bool(false, BoolRepr::YesNo)))
+        ));
+        assert!(matches!(
+            variables.get("enabled"),
+            Some((_, Value::Bool(true, BoolRepr::TrueFalse)))
+        ));
+        assert!(matches!(
+            variables.get("disabled"),
+            Some((_, Value::Bool(false, BoolRepr::TrueFalse)))
+        ));
+    }
+
+    #[test]
+    fn test_nested_object_round_trips_through_the_same_event_stream() {
+        let mut parser = Parser::new("country = { gold_reserves = 100 } }");
+        let value = parser.parse();
+        let variables = match value {
+            Value::Object(variables) => variables,
+            _ => panic!(),
+        };
+        assert!(parser.take_errors().is_empty());
+        match variables.get("country") {
+            Some((_, Value::Object(obj))) => assert_eq!(
+                obj.get("gold_reserves"),
+                Some(&(Operator::Equal, Value::Integer(100)))
+            ),
+            _ => panic!(),
+        }
+    }
+
+    #[test]
+    fn test_merges_three_repeated_scalar_keys_into_an_array() {
+        let mut parser = Parser::new("building = a building = b building = c }");
+        let value = parser.parse();
+        let variables = match value {
+            Value::Object(variables) => variables,
+            _ => panic!(),
+        };
+        assert!(parser.take_errors().is_empty());
+        match variables.get("building") {
+            Some((_, Value::Array(items))) => assert_eq!(items.len(), 3),
+            _ => panic!("expected repeated scalar keys to merge into an array"),
+        }
+    }
+
+    #[test]
+    fn test_merges_three_repeated_object_keys_into_an_array() {
+        let mut parser = Parser::new(
+            "add_modifier = { a = 1 } add_modifier = { b = 2 } add_modifier = { c = 3 } }",
+        );
+        let value = parser.parse();
+        let variables = match value {
+            Value::Object(variables) => variables,
+            _ => panic!(),
+        };
+        assert!(parser.take_errors().is_empty());
+        match variables.get("add_modifier") {
+            Some((_, Value::Array(items))) => assert_eq!(items.len(), 3),
+            _ => panic!("expected repeated object keys to merge into an array"),
+        }
+    }
+
+    #[test]
+    fn test_overwrite_mode_keeps_only_the_last_root_value() {
+        let mut parser = Parser::with_duplicate_key_mode(
+            "building = a building = b }",
+            DuplicateKeyMode::Overwrite,
+        );
+        let value = parser.parse();
+        let variables = match value {
+            Value::Object(variables) => variables,
+            _ => panic!(),
+        };
+        assert!(parser.take_errors().is_empty());
+        assert_eq!(
+            variables.get("building"),
+            Some(&(Operator::Equal, Value::String("b")))
+        );
+    }
+
+    #[test]
+    fn test_error_mode_rejects_a_repeated_root_key() {
+        let mut parser =
+            Parser::with_duplicate_key_mode("building = a building = b }", DuplicateKeyMode::Error);
+        parser.parse();
+        assert_eq!(parser.take_errors().len(), 1);
+    }
+
+    #[test]
+    fn test_bare_sub_object_keys_respect_duplicate_key_mode() {
+        let mut parser = Parser::with_duplicate_key_mode(
+            "country = { tag = a { tag = b } }",
+            DuplicateKeyMode::Error,
+        );
+        parser.parse();
+        assert_eq!(parser.take_errors().len(), 1);
+    }
+}
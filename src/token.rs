@@ -1,15 +1,116 @@
 use logos::Logos;
 
+/// How a boolean literal was spelled in the source, so a future Paradox
+/// serializer can re-emit it the same way instead of always picking one
+/// form.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BoolRepr {
+    /// `yes` / `no`
+    YesNo,
+    /// `true` / `false`
+    TrueFalse,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BoolLiteral {
+    pub value: bool,
+    pub repr: BoolRepr,
+}
+
+/// A lexed `year.month.day[.hour]` literal, e.g. `1836.1.1` or `1836.1.1.12`.
+///
+/// Kept as its own payload type (rather than inline tuple fields) so the
+/// `Date` token variant has a single field, matching how [`Token::Bool`]
+/// carries its payload.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DateLiteral {
+    pub year: i32,
+    pub month: u8,
+    pub day: u8,
+    pub hour: Option<u8>,
+}
+
+/// The error type for [`Token`]'s lexer callbacks. A regex matching the
+/// token's shape doesn't guarantee its components fit the narrower integer
+/// types the payload actually uses (e.g. a `month` past 255 in `1836.999.1`),
+/// so those callbacks report a [`LexError`] instead of panicking.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum LexError {
+    #[default]
+    None,
+    InvalidDate(String),
+}
+
+fn parse_date_literal(slice: &str) -> Result<DateLiteral, LexError> {
+    let invalid = || LexError::InvalidDate(slice.to_owned());
+    let mut parts = slice.split('.');
+    let year = parts.next().ok_or_else(invalid)?.parse().map_err(|_| invalid())?;
+    let month = parts.next().ok_or_else(invalid)?.parse().map_err(|_| invalid())?;
+    let day = parts.next().ok_or_else(invalid)?.parse().map_err(|_| invalid())?;
+    let hour = parts
+        .next()
+        .map(|hour| hour.parse().map_err(|_| invalid()))
+        .transpose()?;
+    Ok(DateLiteral {
+        year,
+        month,
+        day,
+        hour,
+    })
+}
+
+/// The relational/assignment operators Paradox script allows between a key
+/// and its value, e.g. `age >= 20` or `has_trait ?= brave`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operator {
+    /// `=`
+    Equal,
+    /// `?=`
+    ConditionalEqual,
+    /// `==`
+    EqualEqual,
+    /// `!=`
+    NotEqual,
+    /// `<`
+    LessThan,
+    /// `<=`
+    LessThanOrEqual,
+    /// `>`
+    GreaterThan,
+    /// `>=`
+    GreaterThanOrEqual,
+}
+
+impl Operator {
+    /// The literal spelling of the operator, used when exposing it in
+    /// serialized output (see `Value`'s `Serialize` impl).
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Operator::Equal => "=",
+            Operator::ConditionalEqual => "?=",
+            Operator::EqualEqual => "==",
+            Operator::NotEqual => "!=",
+            Operator::LessThan => "<",
+            Operator::LessThanOrEqual => "<=",
+            Operator::GreaterThan => ">",
+            Operator::GreaterThanOrEqual => ">=",
+        }
+    }
+}
+
 /* ANCHOR: tokens */
 #[derive(Debug, Logos, PartialEq)]
+#[logos(error = LexError)]
 // Simple one-liner comments
 #[logos(skip r"#.*\n")]
 // Zero-width space character: https://unicodeplus.com/U+FEFF
 #[logos(skip r"[ ﻿\t\r\n\f]+")]
 pub enum Token<'source> {
-    #[token("false", |_| false, priority = 3)]
-    #[token("true", |_| true, priority = 3)]
-    Bool(bool),
+    #[token("false", |_| BoolLiteral { value: false, repr: BoolRepr::TrueFalse }, priority = 3)]
+    #[token("true", |_| BoolLiteral { value: true, repr: BoolRepr::TrueFalse }, priority = 3)]
+    #[token("no", |_| BoolLiteral { value: false, repr: BoolRepr::YesNo }, priority = 3)]
+    #[token("yes", |_| BoolLiteral { value: true, repr: BoolRepr::YesNo }, priority = 3)]
+    Bool(BoolLiteral),
 
     #[token("{", priority = 1)]
     BraceOpen,
@@ -17,12 +118,27 @@ pub enum Token<'source> {
     #[token("}", priority = 1)]
     BraceClose,
 
-    #[token("=", priority = 1)]
-    EqualSign,
+    // Longer operators must outrank their prefixes, otherwise e.g. `<=`
+    // would lex as `<` followed by a dangling `=`.
+    #[token("=", |_| Operator::Equal, priority = 1)]
+    #[token("?=", |_| Operator::ConditionalEqual, priority = 2)]
+    #[token("==", |_| Operator::EqualEqual, priority = 2)]
+    #[token("!=", |_| Operator::NotEqual, priority = 2)]
+    #[token("<", |_| Operator::LessThan, priority = 1)]
+    #[token("<=", |_| Operator::LessThanOrEqual, priority = 2)]
+    #[token(">", |_| Operator::GreaterThan, priority = 1)]
+    #[token(">=", |_| Operator::GreaterThanOrEqual, priority = 2)]
+    Operator(Operator),
 
     #[token("null", priority = 2)]
     Null,
 
+    // Must outrank `Float` so a three-or-four-component literal like
+    // `1836.1.1` lexes as a date rather than a truncated float, while a
+    // two-component literal like `3.4` still falls through to `Float`.
+    #[regex(r"-?\d+\.\d+\.\d+(?:\.\d+)?", |lex| parse_date_literal(lex.slice()), priority = 4)]
+    Date(DateLiteral),
+
     #[regex(r"-?(?:0|[1-9]\d*)(?:\.\d+)(?:[eE][+-]?\d+)?", |lex| lex.slice().parse::<f64>().unwrap(), priority = 3)]
     Float(f64),
 
@@ -1,27 +1,53 @@
 use ordered_hash_map::OrderedHashMap;
+use serde::ser::SerializeMap;
+
+use crate::token::Operator;
+
+pub use crate::token::BoolRepr;
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum Value<'source> {
     /// null.
     Null,
-    /// true or false.
-    Bool(bool),
+    /// `true`/`false` or `yes`/`no`. The [`BoolRepr`] remembers which
+    /// spelling the source used, so a future Paradox-format serializer can
+    /// round-trip it exactly; JSON serialization always emits a real bool.
+    Bool(bool, BoolRepr),
     /// Any floating point number.
     Float(f64),
     /// Any valid integer
     Integer(i64),
+    /// A `year.month.day[.hour]` literal, e.g. `1836.1.1` or `1836.1.1.12`.
+    Date {
+        year: i32,
+        month: u8,
+        day: u8,
+        hour: Option<u8>,
+    },
     /// Any quoted string.
     String(&'source str),
     /// An array of values
     Array(Vec<Value<'source>>),
-    /// An array of keys and values used to represent variable names and their values
-    Object(OrderedHashMap<&'source str, Value<'source>>),
+    /// An array of keys and values used to represent variable names and
+    /// their values. Every entry carries the [`Operator`] it was assigned
+    /// with (`=`, `?=`, `==`, ...), so e.g. a conditional assignment isn't
+    /// silently indistinguishable from a plain one.
+    Object(OrderedHashMap<&'source str, (Operator, Value<'source>)>),
     /// Since {} can be either an empty Array or an Empty Object, we can use a specific type that covers either case
     /// When serializing empty array/object to JSON, it will default to an empty array: []
     /// Explicit Empty value type is mostly useful when using AST output
     Empty,
 }
 
+/// Wraps a value assigned with anything other than plain `=`, so JSON
+/// output still exposes which operator was used instead of silently
+/// collapsing it to a normal assignment.
+#[derive(serde::Serialize)]
+struct OperatorValue<'a, 'source> {
+    __op: &'static str,
+    value: &'a Value<'source>,
+}
+
 impl serde::Serialize for Value<'_> {
     fn serialize<S>(
         &self,
@@ -34,9 +60,36 @@ impl serde::Serialize for Value<'_> {
             Value::String(s) => serializer.serialize_str(s),
             Value::Float(n) => serializer.serialize_f64(*n),
             Value::Integer(n) => serializer.serialize_i64(*n),
+            Value::Date {
+                year,
+                month,
+                day,
+                hour: None,
+            } => serializer.serialize_str(&format!("{}.{}.{}", year, month, day)),
+            Value::Date {
+                year,
+                month,
+                day,
+                hour: Some(hour),
+            } => serializer.serialize_str(&format!("{}.{}.{}.{}", year, month, day, hour)),
             Value::Array(arr) => arr.serialize(serializer),
-            Value::Object(obj) => obj.serialize(serializer),
-            Value::Bool(b) => serializer.serialize_bool(*b),
+            Value::Object(obj) => {
+                let mut map = serializer.serialize_map(Some(obj.len()))?;
+                for (key, (op, value)) in obj.iter() {
+                    match op {
+                        Operator::Equal => map.serialize_entry(key, value)?,
+                        _ => map.serialize_entry(
+                            key,
+                            &OperatorValue {
+                                __op: op.as_str(),
+                                value,
+                            },
+                        )?,
+                    }
+                }
+                map.end()
+            }
+            Value::Bool(b, _) => serializer.serialize_bool(*b),
             Value::Null => serializer.serialize_none(),
             Value::Empty => Vec::<serde_json::Value>::new().serialize(serializer),
         }
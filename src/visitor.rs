@@ -0,0 +1,305 @@
+use logos::{Lexer, Logos, Span};
+
+use crate::parse_simple_value::parse_simple_value;
+use crate::token::{LexError, Operator, Token};
+use crate::value::Value;
+use crate::Error;
+
+/// Receives parse events as the token stream is walked, without ever
+/// materializing a full `Value` tree.
+///
+/// Following combine's model of zero-copy streaming parsing, all `&str`
+/// payloads handed to a visitor borrow directly from the source buffer, so a
+/// caller can `mmap` a multi-hundred-MB save file and, say, only look at
+/// `gold_reserves` inside every `country=` block, in roughly constant
+/// memory.
+pub trait Visitor<'source> {
+    fn object_start(&mut self, _span: Span) {}
+    fn object_close(&mut self, _span: Span) {}
+    fn key(&mut self, _key: &'source str, _operator: Operator, _span: Span) {}
+    fn scalar(&mut self, _value: Value<'source>, _span: Span) {}
+}
+
+/// Drive `source` through the lexer, feeding `visitor` as each token is
+/// consumed, and return the diagnostics collected along the way.
+///
+/// Parsing never stops at the first malformed statement: like
+/// [`crate::parser::Parser`], it records the error and resynchronizes at the
+/// next safe point, so a single pass still surfaces every problem.
+pub fn parse_events<'source>(
+    source: &'source str,
+    visitor: &mut impl Visitor<'source>,
+) -> Vec<Error> {
+    let mut lexer = Token::lexer(source);
+    let mut errors = Vec::new();
+
+    // The root scope has no literal braces, so it gets an empty span.
+    visitor.object_start(Span::default());
+    parse_object_contents(&mut lexer, visitor, &mut errors);
+    visitor.object_close(Span::default());
+
+    errors
+}
+
+/// Root-scope content: every entry must be a `key operator value` triple,
+/// mirroring [`crate::parser::Parser`]'s top-level behavior.
+fn parse_object_contents<'source>(
+    lexer: &mut Lexer<'source, Token<'source>>,
+    visitor: &mut impl Visitor<'source>,
+    errors: &mut Vec<Error>,
+) {
+    let mut current_key: Option<&str> = None;
+
+    while let Some(token) = lexer.next() {
+        match token {
+            Ok(Token::BraceClose) => {
+                if current_key.is_some() {
+                    errors.push((
+                        "unexpected '}', expected an operator followed by value after key"
+                            .to_owned(),
+                        lexer.span(),
+                    ));
+                }
+                return;
+            }
+
+            Ok(Token::Any(key)) if current_key.is_none() => {
+                current_key = Some(key);
+            }
+
+            Ok(Token::Operator(op)) if current_key.is_some() => {
+                let key = current_key.take().unwrap();
+                visitor.key(key, op, lexer.span());
+                if let Err(err) = emit_value(lexer, visitor, errors) {
+                    errors.push(err);
+                    match synchronize(lexer) {
+                        Some(Token::Any(key)) => current_key = Some(key),
+                        _ => return,
+                    }
+                }
+            }
+
+            Err(err) => {
+                errors.push((lex_error_message(&err), lexer.span()));
+                match synchronize(lexer) {
+                    Some(Token::Any(key)) => current_key = Some(key),
+                    _ => return,
+                }
+            }
+
+            _ => {
+                errors.push((
+                    format!(
+                        "unexpected token '{:?}' in object context, current_key: {:?}",
+                        token, current_key
+                    ),
+                    lexer.span(),
+                ));
+                match synchronize(lexer) {
+                    Some(Token::Any(key)) => current_key = Some(key),
+                    _ => return,
+                }
+            }
+        }
+    }
+}
+
+/// Array-or-object scope content: an `Any` token may turn out to be a bare
+/// scalar or the key of a nested `key operator value` entry, decided by
+/// looking at the following token (see [`handle_identifier`]).
+fn parse_value_contents<'source>(
+    lexer: &mut Lexer<'source, Token<'source>>,
+    visitor: &mut impl Visitor<'source>,
+    errors: &mut Vec<Error>,
+) {
+    while let Some(token) = lexer.next() {
+        match token {
+            Ok(Token::BraceClose) => return,
+            Ok(Token::BraceOpen) => {
+                let open_span = lexer.span();
+                visitor.object_start(open_span.clone());
+                parse_value_contents(lexer, visitor, errors);
+                let close_span = lexer.span();
+                visitor.object_close(open_span.start..close_span.end);
+            }
+            Ok(Token::Any(identifier)) => {
+                if handle_identifier(identifier, lexer, visitor, errors) {
+                    return;
+                }
+            }
+            Ok(other) => emit_simple_token(other, visitor, errors, lexer.span()),
+            Err(err) => errors.push((lex_error_message(&err), lexer.span())),
+        }
+    }
+
+    errors.push(("Unmatched opening bracket".to_owned(), lexer.span()));
+}
+
+/// Resolve an `Any` token seen inside array-or-object content: it's either
+/// the key of a nested `key operator value` entry, or a bare scalar,
+/// decided by looking at the token that follows. Returns `true` if the
+/// enclosing scope has been closed and the caller should stop.
+fn handle_identifier<'source>(
+    identifier: &'source str,
+    lexer: &mut Lexer<'source, Token<'source>>,
+    visitor: &mut impl Visitor<'source>,
+    errors: &mut Vec<Error>,
+) -> bool {
+    let identifier_span = lexer.span();
+    match lexer.next() {
+        Some(Ok(Token::Operator(op))) => {
+            visitor.key(identifier, op, lexer.span());
+            if let Err(err) = emit_value(lexer, visitor, errors) {
+                errors.push(err);
+                match synchronize(lexer) {
+                    Some(Token::Any(key)) => return handle_identifier(key, lexer, visitor, errors),
+                    Some(Token::BraceClose) | None => return true,
+                    _ => (),
+                }
+            }
+            false
+        }
+        Some(Ok(Token::BraceClose)) => {
+            visitor.scalar(Value::String(identifier), identifier_span);
+            true
+        }
+        Some(Ok(other)) => {
+            visitor.scalar(Value::String(identifier), identifier_span);
+            emit_simple_token(other, visitor, errors, lexer.span());
+            false
+        }
+        _ => {
+            errors.push((
+                "unexpected token after identifier in array".to_owned(),
+                lexer.span(),
+            ));
+            true
+        }
+    }
+}
+
+fn emit_value<'source>(
+    lexer: &mut Lexer<'source, Token<'source>>,
+    visitor: &mut impl Visitor<'source>,
+    errors: &mut Vec<Error>,
+) -> Result<(), Error> {
+    match lexer.next() {
+        Some(Ok(Token::BraceOpen)) => {
+            let open_span = lexer.span();
+            visitor.object_start(open_span.clone());
+            parse_value_contents(lexer, visitor, errors);
+            let close_span = lexer.span();
+            visitor.object_close(open_span.start..close_span.end);
+            Ok(())
+        }
+        Some(Ok(Token::BraceClose)) => Err((
+            "unexpected '}' when expecting value".to_owned(),
+            lexer.span(),
+        )),
+        Some(Ok(token)) => {
+            emit_simple_token(token, visitor, errors, lexer.span());
+            Ok(())
+        }
+        Some(Err(err)) => Err((lex_error_message(&err), lexer.span())),
+        None => Err(("expected value".to_owned(), lexer.span())),
+    }
+}
+
+fn emit_simple_token<'source>(
+    token: Token<'source>,
+    visitor: &mut impl Visitor<'source>,
+    errors: &mut Vec<Error>,
+    span: Span,
+) {
+    match parse_simple_value(token) {
+        Ok(value) => visitor.scalar(value, span),
+        Err((msg, _)) => errors.push((msg, span)),
+    }
+}
+
+fn lex_error_message(err: &LexError) -> String {
+    match err {
+        LexError::None => "invalid token".to_owned(),
+        LexError::InvalidDate(slice) => format!("invalid date literal '{}'", slice),
+    }
+}
+
+/// Skip tokens until a safe resumption point: a `BraceClose` that returns us
+/// to the depth at which the error occurred, or the next `Any` key seen at
+/// that same depth. Same resynchronization invariant as the tree-building
+/// `Parser`.
+fn synchronize<'source>(lexer: &mut Lexer<'source, Token<'source>>) -> Option<Token<'source>> {
+    let mut depth = 0i32;
+    for token in lexer.by_ref() {
+        match token {
+            Ok(Token::BraceOpen) => depth += 1,
+            Ok(Token::BraceClose) => {
+                if depth == 0 {
+                    return Some(Token::BraceClose);
+                }
+                depth -= 1;
+            }
+            Ok(Token::Any(key)) if depth == 0 => return Some(Token::Any(key)),
+            _ => (),
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Default)]
+    struct RecordingVisitor<'source> {
+        keys: Vec<&'source str>,
+        scalars: Vec<Value<'source>>,
+        object_starts: usize,
+        object_closes: usize,
+    }
+
+    impl<'source> Visitor<'source> for RecordingVisitor<'source> {
+        fn object_start(&mut self, _span: Span) {
+            self.object_starts += 1;
+        }
+        fn object_close(&mut self, _span: Span) {
+            self.object_closes += 1;
+        }
+        fn key(&mut self, key: &'source str, _operator: Operator, _span: Span) {
+            self.keys.push(key);
+        }
+        fn scalar(&mut self, value: Value<'source>, _span: Span) {
+            self.scalars.push(value);
+        }
+    }
+
+    #[test]
+    fn test_emits_events_for_flat_object() {
+        let mut visitor = RecordingVisitor::default();
+        let errors = parse_events("key1 = value1 key2 = 2 }", &mut visitor);
+        assert!(errors.is_empty());
+        assert_eq!(visitor.keys, vec!["key1", "key2"]);
+        assert_eq!(visitor.scalars.len(), 2);
+        // One object_start/close pair for the implicit root scope.
+        assert_eq!(visitor.object_starts, 1);
+        assert_eq!(visitor.object_closes, 1);
+    }
+
+    #[test]
+    fn test_emits_events_for_nested_object() {
+        let mut visitor = RecordingVisitor::default();
+        let errors = parse_events("country = { gold_reserves = 100 } }", &mut visitor);
+        assert!(errors.is_empty());
+        assert_eq!(visitor.keys, vec!["country", "gold_reserves"]);
+        // Root scope plus the nested `country` block.
+        assert_eq!(visitor.object_starts, 2);
+        assert_eq!(visitor.object_closes, 2);
+    }
+
+    #[test]
+    fn test_date_component_out_of_range_is_reported_not_a_panic() {
+        let mut visitor = RecordingVisitor::default();
+        let errors = parse_events("start_date = 1836.999.1 }", &mut visitor);
+        assert_eq!(errors.len(), 1);
+    }
+}